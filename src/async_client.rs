@@ -0,0 +1,185 @@
+/**
+ * AsyncClient mirrors `Client`, but its methods are `async fn`s so they
+ * can be awaited from a service that fetches many repos concurrently
+ * instead of blocking the calling thread on each Codecov request or
+ * cache access.
+ *
+ * `codecov::Client` is itself synchronous, so each Codecov HTTP request
+ * runs on the blocking thread pool via `tokio::task::spawn_blocking`;
+ * the cache-hit fast path never touches it and goes straight through
+ * `tokio::fs`, so concurrent calls for different branches/commits can
+ * proceed in parallel.
+ */
+use std::sync::Arc;
+
+use codecov::{
+    author::Author, branch_detail::BranchDetailAPIResponse, branches::BranchesAPIResponse,
+    commits::CommitsAPIResponse, owner::Owner, repos::Repo, Client as CodecovClient,
+};
+
+use crate::cache;
+use crate::config;
+use crate::errors::Error;
+
+pub struct AsyncClient {
+    codecov_client: Arc<CodecovClient>,
+    // Keyed by an immutable commit id, so entries never go stale.
+    cache_client: cache::Client,
+    // Used for responses that can change over time, so entries expire.
+    mutable_cache_client: cache::Client,
+}
+
+impl AsyncClient {
+    pub fn new_from_env() -> Result<AsyncClient, Error> {
+        let cache_dir = config::resolve_cache_dir_root();
+        let compress = config::resolve_compress_from_env();
+        Ok(AsyncClient {
+            codecov_client: Arc::new(CodecovClient::new_from_env()?),
+            cache_client: cache::Client::new(cache_dir.clone(), "data.json".to_string())
+                .with_compression(compress),
+            mutable_cache_client: cache::Client::new_with_ttl(
+                cache_dir,
+                "data.json".to_string(),
+                config::MUTABLE_CACHE_TTL,
+            )
+            .with_compression(compress),
+        })
+    }
+
+    pub fn new(token: String, cache_dir: std::path::PathBuf) -> AsyncClient {
+        AsyncClient {
+            codecov_client: Arc::new(CodecovClient::new(token)),
+            cache_client: cache::Client::new(cache_dir.clone(), "data.json".to_string()),
+            mutable_cache_client: cache::Client::new_with_ttl(
+                cache_dir,
+                "data.json".to_string(),
+                config::MUTABLE_CACHE_TTL,
+            ),
+        }
+    }
+
+    /**
+     * get_all_repos returns a list of all repos for a given owner.
+     * /repos endpoint returns a list of repos for a given owner with pagination.
+     * This function will make multiple requests to get all repos.
+     */
+    pub async fn get_all_repos(&self, owner: &Owner) -> Result<Vec<Repo>, Error> {
+        let cache_key = &[&owner.service, &owner.username, "repos"];
+        if let Ok(Some(repos)) = self
+            .mutable_cache_client
+            .load_typed_fresh_async(cache_key)
+            .await
+        {
+            return Ok(repos);
+        }
+        let codecov_client = self.codecov_client.clone();
+        let owner_for_task = owner.clone();
+        let repos = tokio::task::spawn_blocking(move || {
+            codecov_client.get_all_repos(&owner_for_task)
+        })
+        .await
+        .expect("get_all_repos task panicked")?;
+        self.mutable_cache_client
+            .save_typed_async(cache_key, &repos)
+            .await?;
+        Ok(repos)
+    }
+
+    /**
+     * get_commits returns a list of commits for a given author.
+     * https://docs.codecov.com/reference/repos_commits_list
+     */
+    pub async fn get_commits(&self, author: &Author) -> Result<CommitsAPIResponse, Error> {
+        let cache_key = &[&author.service, &author.username, &author.name, "commits"];
+        if let Ok(Some(commits)) = self
+            .mutable_cache_client
+            .load_typed_fresh_async(cache_key)
+            .await
+        {
+            return Ok(commits);
+        }
+        let codecov_client = self.codecov_client.clone();
+        let author_for_task = author.clone();
+        let commits = tokio::task::spawn_blocking(move || {
+            codecov_client.get_commits(&author_for_task)
+        })
+        .await
+        .expect("get_commits task panicked")?;
+        self.mutable_cache_client
+            .save_typed_async(cache_key, &commits)
+            .await?;
+        Ok(commits)
+    }
+
+    /**
+     * get_branches returns a list of branches for a given author.
+     * https://docs.codecov.com/reference/repos_branches_list
+     */
+    pub async fn get_branches(&self, author: &Author) -> Result<BranchesAPIResponse, Error> {
+        let cache_key = &[&author.service, &author.username, &author.name, "branches"];
+        if let Ok(Some(branches)) = self
+            .mutable_cache_client
+            .load_typed_fresh_async(cache_key)
+            .await
+        {
+            return Ok(branches);
+        }
+        let codecov_client = self.codecov_client.clone();
+        let author_for_task = author.clone();
+        let branches = tokio::task::spawn_blocking(move || {
+            codecov_client.get_branches(&author_for_task)
+        })
+        .await
+        .expect("get_branches task panicked")?;
+        self.mutable_cache_client
+            .save_typed_async(cache_key, &branches)
+            .await?;
+        Ok(branches)
+    }
+
+    /**
+     * get_branch_detail_with_commit_id returns a branch detail for a
+     * given author, branch name, and commit id.
+     * https://docs.codecov.com/reference/repos_branches_retrieve
+     */
+    pub async fn get_branch_detail_with_commit_id(
+        &self,
+        author: &Author,
+        branch_name: &str,
+        commit_id: &str,
+    ) -> Result<BranchDetailAPIResponse, Error> {
+        let cache_key = &[
+            &author.service,
+            &author.username,
+            &author.name,
+            branch_name,
+            commit_id,
+        ];
+        // Use cache if exists
+        if let Ok(Some(branch_detail)) = self.cache_client.load_typed_fresh_async(cache_key).await {
+            return Ok(branch_detail);
+        }
+        // If cache does not exist, fetch from Codecov API
+        let codecov_client = self.codecov_client.clone();
+        let author_for_task = author.clone();
+        let branch_name_for_task = branch_name.to_string();
+        let retrieved = tokio::task::spawn_blocking(move || {
+            codecov_client.get_branch_detail(&author_for_task, &branch_name_for_task)
+        })
+        .await
+        .expect("get_branch_detail task panicked")?;
+        // Save to cache
+        if let BranchDetailAPIResponse::Success(detail) = &retrieved {
+            let cache_key = &[
+                &author.service,
+                &author.username,
+                &author.name,
+                branch_name,
+                &detail.head_commit.commitid,
+            ];
+            self.cache_client.save_typed_async(cache_key, detail).await?;
+            return Ok(retrieved);
+        }
+        Ok(retrieved)
+    }
+}
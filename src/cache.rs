@@ -7,12 +7,75 @@
  * cache_dir/{key1}/{key2}/../{key_n}/data
  *
  */
-use std::{fs, io::Write, path::PathBuf};
+use std::{
+    fs,
+    io::{Read, Write},
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// gzip streams start with this two-byte magic, which lets `load` tell
+/// compressed entries apart from plain ones without a separate flag.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 pub mod errors;
 
+/**
+ * Clock abstracts the current time so that TTL expiry can be tested
+ * without waiting on a real clock. `Send + Sync` so a `cache::Client`
+ * (and thus `AsyncClient`) can be shared across tasks on a
+ * multi-threaded async runtime.
+ */
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/**
+ * SystemClock is the default Clock, backed by the OS clock.
+ */
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/**
+ * Meta is the sidecar record written next to a cache entry when the
+ * Client is configured with a TTL, so expiry can be checked without
+ * relying solely on filesystem mtime.
+ */
+#[derive(Serialize, Deserialize)]
+struct Meta {
+    written_at: u64,
+}
+
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/**
+ * tmp_suffix returns a value unique enough (within this process) to
+ * avoid two concurrent writers colliding on the same temp file name.
+ */
+fn tmp_suffix() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let counter = TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{}-{}", std::process::id(), nanos, counter)
+}
+
 pub struct Client {
     cache_dir: PathBuf,
     filename: String,
+    ttl: Option<Duration>,
+    clock: Box<dyn Clock + Send + Sync>,
+    compress: bool,
 }
 
 impl Client {
@@ -20,9 +83,45 @@ impl Client {
         Client {
             cache_dir,
             filename,
+            ttl: None,
+            clock: Box::new(SystemClock),
+            compress: false,
         }
     }
 
+    /**
+     * new_with_ttl creates a Client that treats entries older than `ttl`
+     * as absent, which is useful for endpoints whose responses aren't
+     * keyed by an immutable id (e.g. branch or repo listings).
+     */
+    pub fn new_with_ttl(cache_dir: PathBuf, filename: String, ttl: Duration) -> Client {
+        Client {
+            cache_dir,
+            filename,
+            ttl: Some(ttl),
+            clock: Box::new(SystemClock),
+            compress: false,
+        }
+    }
+
+    /**
+     * new_compressed creates a Client that gzip-compresses payloads on
+     * save. Existing uncompressed entries still load, since `load`
+     * detects the format by magic bytes rather than trusting this flag.
+     */
+    pub fn new_compressed(cache_dir: PathBuf, filename: String) -> Client {
+        Client::new(cache_dir, filename).with_compression(true)
+    }
+
+    /**
+     * with_compression toggles gzip compression, so it can be combined
+     * with `new_with_ttl` for endpoints that need both.
+     */
+    pub fn with_compression(mut self, compress: bool) -> Client {
+        self.compress = compress;
+        self
+    }
+
     fn dirpath_by_keys(&self, keys: &[&str]) -> PathBuf {
         let mut path = self.cache_dir.clone();
         for key in keys {
@@ -37,30 +136,228 @@ impl Client {
         path
     }
 
+    fn meta_filepath_by_keys(&self, keys: &[&str]) -> PathBuf {
+        let mut path = self.dirpath_by_keys(keys);
+        path.push("meta.json");
+        path
+    }
+
     fn ensure_dir(&self, keys: &[&str]) -> Result<(), errors::Error> {
         let path = self.dirpath_by_keys(keys);
-        fs::create_dir_all(path)?;
-        Ok(())
+        fs::create_dir_all(&path).map_err(|source| errors::Error::WriteFailed { path, source })
+    }
+
+    /**
+     * written_at returns when an entry was written, preferring the
+     * meta.json sidecar and falling back to the data file's mtime when
+     * no sidecar is present (e.g. entries written before TTL support,
+     * or by a Client without a TTL, which never writes one).
+     */
+    fn written_at(&self, keys: &[&str]) -> Option<SystemTime> {
+        let meta_path = self.meta_filepath_by_keys(keys);
+        if let Ok(data) = fs::read(&meta_path) {
+            if let Ok(meta) = serde_json::from_slice::<Meta>(&data) {
+                return Some(UNIX_EPOCH + Duration::from_secs(meta.written_at));
+            }
+        }
+        let data_path = self.filepath_by_keys(keys);
+        fs::metadata(data_path).and_then(|m| m.modified()).ok()
+    }
+
+    /**
+     * is_expired reports whether the entry at `keys` is older than the
+     * configured TTL. A Client without a TTL never expires entries, and
+     * an entry with no writable timestamp is treated as fresh so that a
+     * genuinely missing entry still surfaces as "not found" rather than
+     * "expired".
+     */
+    fn is_expired(&self, keys: &[&str]) -> bool {
+        let Some(ttl) = self.ttl else {
+            return false;
+        };
+        match self.written_at(keys) {
+            Some(written_at) => match self.clock.now().duration_since(written_at) {
+                Ok(elapsed) => elapsed > ttl,
+                Err(_) => false,
+            },
+            None => false,
+        }
+    }
+
+    fn write_meta(&self, keys: &[&str]) -> Result<(), errors::Error> {
+        let meta_path = self.meta_filepath_by_keys(keys);
+        let written_at = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let data = serde_json::to_vec(&Meta { written_at })
+            .map_err(|source| errors::Error::SerializeFailed { source })?;
+        fs::write(&meta_path, data)
+            .map_err(|source| errors::Error::WriteFailed { path: meta_path, source })
     }
 
     /**
      * Save data to cache with specified keys.
+     *
+     * Data is written to a randomized temp file in the same directory
+     * first, then renamed over the final path. Rename is atomic on the
+     * same filesystem, so a crash or a concurrent writer can never leave
+     * behind a truncated entry, which matters because multiple
+     * processes may share one CODECOV_CACHE_DIR.
      */
     pub fn save(&self, keys: &[&str], data: &[u8]) -> Result<(), errors::Error> {
         let path = self.filepath_by_keys(keys);
         self.ensure_dir(keys)?;
-        let file = fs::File::create(path)?;
-        let mut writer = std::io::BufWriter::new(file);
-        writer.write_all(data)?;
+        let payload = if self.compress {
+            self.gzip(data)
+        } else {
+            data.to_vec()
+        };
+        let tmp_path = path.with_file_name(format!("{}.{}.tmp", self.filename, tmp_suffix()));
+        let write_result = (|| -> Result<(), errors::Error> {
+            let to_write_failed = |source| errors::Error::WriteFailed {
+                path: tmp_path.clone(),
+                source,
+            };
+            let file = fs::File::create(&tmp_path).map_err(to_write_failed)?;
+            let mut writer = std::io::BufWriter::new(file);
+            writer.write_all(&payload).map_err(to_write_failed)?;
+            writer.flush().map_err(to_write_failed)?;
+            writer.get_ref().sync_all().map_err(to_write_failed)?;
+            Ok(())
+        })();
+        if let Err(err) = write_result {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+        fs::rename(&tmp_path, &path).map_err(|source| errors::Error::WriteFailed {
+            path: path.clone(),
+            source,
+        })?;
+        if self.ttl.is_some() {
+            self.write_meta(keys)?;
+        }
         Ok(())
     }
 
     /**
-     * Load data from cache with specified keys.
+     * gzip compresses `data` into a new in-memory buffer. Writing into a
+     * `Vec<u8>` cannot fail, so this never needs to surface an error.
+     */
+    fn gzip(&self, data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(data)
+            .expect("writing into an in-memory buffer cannot fail");
+        encoder
+            .finish()
+            .expect("writing into an in-memory buffer cannot fail")
+    }
+
+    /**
+     * gunzip_if_needed transparently decompresses a payload that starts
+     * with the gzip magic bytes, leaving plain payloads untouched so
+     * caches written before compression was enabled still load. `path`
+     * is only used to give a decompression failure useful context.
+     */
+    fn gunzip_if_needed(&self, path: &PathBuf, raw: Vec<u8>) -> Result<Vec<u8>, errors::Error> {
+        if raw.starts_with(&GZIP_MAGIC) {
+            let mut decoder = GzDecoder::new(&raw[..]);
+            let mut decompressed = Vec::new();
+            decoder
+                .read_to_end(&mut decompressed)
+                .map_err(|source| errors::Error::ReadFailed {
+                    path: path.clone(),
+                    source,
+                })?;
+            Ok(decompressed)
+        } else {
+            Ok(raw)
+        }
+    }
+
+    /**
+     * Load data from cache with specified keys. An entry older than the
+     * configured TTL is treated the same as a missing one.
      */
     pub fn load(&self, keys: &[&str]) -> Result<Vec<u8>, errors::Error> {
         let path = self.filepath_by_keys(keys);
-        Ok(fs::read(path)?)
+        if self.is_expired(keys) {
+            return Err(errors::Error::ReadFailed {
+                path,
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "cache entry expired"),
+            });
+        }
+        let raw = fs::read(&path).map_err(|source| errors::Error::ReadFailed {
+            path: path.clone(),
+            source,
+        })?;
+        self.gunzip_if_needed(&path, raw)
+    }
+
+    /**
+     * load_fresh is like load, but reports expiry as `Ok(None)` instead
+     * of an error, so callers can refetch without special-casing expiry
+     * in their error handling.
+     */
+    pub fn load_fresh(&self, keys: &[&str]) -> Result<Option<Vec<u8>>, errors::Error> {
+        if self.is_expired(keys) {
+            return Ok(None);
+        }
+        match self.load(keys) {
+            Ok(data) => Ok(Some(data)),
+            Err(errors::Error::ReadFailed { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /**
+     * save_typed serializes `value` to JSON and saves it, so callers
+     * don't have to hand-roll `serde_json::to_vec` at each call site.
+     */
+    pub fn save_typed<T: Serialize>(&self, keys: &[&str], value: &T) -> Result<(), errors::Error> {
+        let data = serde_json::to_vec(value)
+            .map_err(|source| errors::Error::SerializeFailed { source })?;
+        self.save(keys, &data)
+    }
+
+    /**
+     * load_typed loads and deserializes a cache entry as `T`.
+     */
+    pub fn load_typed<T: DeserializeOwned>(&self, keys: &[&str]) -> Result<T, errors::Error> {
+        let path = self.filepath_by_keys(keys);
+        let data = self.load(keys)?;
+        serde_json::from_slice(&data).map_err(|source| errors::Error::DeserializeFailed {
+            path,
+            source,
+        })
+    }
+
+    /**
+     * load_typed_fresh is the typed counterpart of `load_fresh`: it
+     * returns `Ok(None)` when the entry is missing or expired instead
+     * of an error.
+     */
+    pub fn load_typed_fresh<T: DeserializeOwned>(
+        &self,
+        keys: &[&str],
+    ) -> Result<Option<T>, errors::Error> {
+        match self.load_fresh(keys)? {
+            Some(data) => {
+                let path = self.filepath_by_keys(keys);
+                let value = serde_json::from_slice(&data).map_err(|source| {
+                    errors::Error::DeserializeFailed { path, source }
+                })?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
     }
 
     /**
@@ -68,15 +365,368 @@ impl Client {
      */
     pub fn remove(&self, keys: &[&str]) -> Result<(), errors::Error> {
         let path = self.filepath_by_keys(keys);
-        fs::remove_file(path)?;
+        fs::remove_file(&path).map_err(|source| errors::Error::RemoveFailed {
+            path: path.clone(),
+            source,
+        })?;
+        let meta_path = self.meta_filepath_by_keys(keys);
+        let _ = fs::remove_file(meta_path);
         Ok(())
     }
 
     /**
-     * Check if cache exists with specified keys.
+     * Check if cache exists with specified keys. An expired entry is
+     * reported as absent.
      */
     pub fn has(&self, keys: &[&str]) -> bool {
+        if self.is_expired(keys) {
+            return false;
+        }
         let path = self.filepath_by_keys(keys);
         path.exists()
     }
+
+    /**
+     * save_async is the `tokio::fs`-backed counterpart of `save`, for
+     * use from `AsyncClient` so a cache write never blocks the calling
+     * thread.
+     */
+    #[cfg(feature = "async")]
+    pub async fn save_async(&self, keys: &[&str], data: &[u8]) -> Result<(), errors::Error> {
+        let path = self.filepath_by_keys(keys);
+        let dir_path = self.dirpath_by_keys(keys);
+        tokio::fs::create_dir_all(&dir_path)
+            .await
+            .map_err(|source| errors::Error::WriteFailed {
+                path: dir_path,
+                source,
+            })?;
+        let payload = if self.compress {
+            self.gzip(data)
+        } else {
+            data.to_vec()
+        };
+        let tmp_path = path.with_file_name(format!("{}.{}.tmp", self.filename, tmp_suffix()));
+        if let Err(source) = tokio::fs::write(&tmp_path, &payload).await {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return Err(errors::Error::WriteFailed {
+                path: tmp_path,
+                source,
+            });
+        }
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .map_err(|source| errors::Error::WriteFailed {
+                path: path.clone(),
+                source,
+            })?;
+        if self.ttl.is_some() {
+            let meta_path = self.meta_filepath_by_keys(keys);
+            let written_at = self
+                .clock
+                .now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let data = serde_json::to_vec(&Meta { written_at })
+                .map_err(|source| errors::Error::SerializeFailed { source })?;
+            tokio::fs::write(&meta_path, data)
+                .await
+                .map_err(|source| errors::Error::WriteFailed {
+                    path: meta_path,
+                    source,
+                })?;
+        }
+        Ok(())
+    }
+
+    /**
+     * load_async is the `tokio::fs`-backed counterpart of `load`. TTL
+     * expiry still relies on a sync `stat`/read of the small meta file,
+     * which is cheap enough not to warrant its own blocking task.
+     */
+    #[cfg(feature = "async")]
+    pub async fn load_async(&self, keys: &[&str]) -> Result<Vec<u8>, errors::Error> {
+        let path = self.filepath_by_keys(keys);
+        if self.is_expired(keys) {
+            return Err(errors::Error::ReadFailed {
+                path,
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "cache entry expired"),
+            });
+        }
+        let raw = tokio::fs::read(&path)
+            .await
+            .map_err(|source| errors::Error::ReadFailed {
+                path: path.clone(),
+                source,
+            })?;
+        self.gunzip_if_needed(&path, raw)
+    }
+
+    /**
+     * load_fresh_async is the async counterpart of `load_fresh`.
+     */
+    #[cfg(feature = "async")]
+    pub async fn load_fresh_async(&self, keys: &[&str]) -> Result<Option<Vec<u8>>, errors::Error> {
+        if self.is_expired(keys) {
+            return Ok(None);
+        }
+        match self.load_async(keys).await {
+            Ok(data) => Ok(Some(data)),
+            Err(errors::Error::ReadFailed { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /**
+     * save_typed_async is the async counterpart of `save_typed`.
+     */
+    #[cfg(feature = "async")]
+    pub async fn save_typed_async<T: Serialize>(
+        &self,
+        keys: &[&str],
+        value: &T,
+    ) -> Result<(), errors::Error> {
+        let data = serde_json::to_vec(value)
+            .map_err(|source| errors::Error::SerializeFailed { source })?;
+        self.save_async(keys, &data).await
+    }
+
+    /**
+     * load_typed_fresh_async is the async counterpart of
+     * `load_typed_fresh`.
+     */
+    #[cfg(feature = "async")]
+    pub async fn load_typed_fresh_async<T: DeserializeOwned>(
+        &self,
+        keys: &[&str],
+    ) -> Result<Option<T>, errors::Error> {
+        match self.load_fresh_async(keys).await? {
+            Some(data) => {
+                let path = self.filepath_by_keys(keys);
+                let value = serde_json::from_slice(&data).map_err(|source| {
+                    errors::Error::DeserializeFailed { path, source }
+                })?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct FakeClock(Arc<Mutex<SystemTime>>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!(
+            "rust-codecov-cache-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_entry_is_treated_as_missing_after_ttl() {
+        let cache_dir = temp_cache_dir("ttl-expiry");
+        let clock = Arc::new(Mutex::new(SystemTime::now()));
+        let client = Client {
+            cache_dir: cache_dir.clone(),
+            filename: "data".to_string(),
+            ttl: Some(Duration::from_secs(60)),
+            clock: Box::new(FakeClock(clock.clone())),
+            compress: false,
+        };
+
+        client.save(&["owner", "repo"], b"hello").unwrap();
+        assert!(client.has(&["owner", "repo"]));
+        assert_eq!(
+            client.load_fresh(&["owner", "repo"]).unwrap(),
+            Some(b"hello".to_vec())
+        );
+
+        // Fast-forward the clock past the TTL.
+        *clock.lock().unwrap() += Duration::from_secs(61);
+
+        assert!(!client.has(&["owner", "repo"]));
+        assert!(client.load(&["owner", "repo"]).is_err());
+        assert_eq!(client.load_fresh(&["owner", "repo"]).unwrap(), None);
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_save_keeps_previous_entry_intact_if_interrupted_before_rename() {
+        let cache_dir = temp_cache_dir("atomic-save");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+        client.save(&["owner", "repo"], b"v1").unwrap();
+
+        // Simulate a crash between writing the temp file and renaming it
+        // over the real entry: leave a temp file behind without renaming.
+        let dir = client.dirpath_by_keys(&["owner", "repo"]);
+        fs::write(dir.join("data.simulated-crash.tmp"), b"v2-partial").unwrap();
+
+        assert_eq!(client.load(&["owner", "repo"]).unwrap(), b"v1".to_vec());
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_save_overwrites_entry_atomically() {
+        let cache_dir = temp_cache_dir("atomic-save-overwrite");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+        client.save(&["owner", "repo"], b"v1").unwrap();
+        client.save(&["owner", "repo"], b"v2").unwrap();
+        assert_eq!(client.load(&["owner", "repo"]).unwrap(), b"v2".to_vec());
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_compressed_entry_round_trips() {
+        let cache_dir = temp_cache_dir("compressed");
+        let client = Client::new_compressed(cache_dir.clone(), "data".to_string());
+
+        let data = b"hello hello hello hello hello hello hello".repeat(10);
+        client.save(&["owner", "repo"], &data).unwrap();
+        assert_eq!(client.load(&["owner", "repo"]).unwrap(), data);
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_compressed_client_still_loads_uncompressed_entries() {
+        let cache_dir = temp_cache_dir("compressed-backcompat");
+        let plain_client = Client::new(cache_dir.clone(), "data".to_string());
+        plain_client.save(&["owner", "repo"], b"hello").unwrap();
+
+        let compressed_client = Client::new_compressed(cache_dir.clone(), "data".to_string());
+        assert_eq!(
+            compressed_client.load(&["owner", "repo"]).unwrap(),
+            b"hello".to_vec()
+        );
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_load_error_names_the_missing_path() {
+        let cache_dir = temp_cache_dir("missing-entry-error");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+
+        let err = client.load(&["owner", "repo"]).unwrap_err();
+        match err {
+            errors::Error::ReadFailed { path, .. } => {
+                assert_eq!(path, client.filepath_by_keys(&["owner", "repo"]));
+            }
+            other => panic!("expected ReadFailed, got {:?}", other),
+        }
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_entry_without_ttl_never_expires() {
+        let cache_dir = temp_cache_dir("no-ttl");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+
+        client.save(&["owner", "repo"], b"hello").unwrap();
+        assert!(client.has(&["owner", "repo"]));
+        assert_eq!(client.load(&["owner", "repo"]).unwrap(), b"hello".to_vec());
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_save_typed_and_load_typed_round_trip() {
+        let cache_dir = temp_cache_dir("typed-round-trip");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Widget {
+            name: String,
+            count: u32,
+        }
+        let widget = Widget {
+            name: "sprocket".to_string(),
+            count: 3,
+        };
+
+        client.save_typed(&["owner", "repo"], &widget).unwrap();
+        let loaded: Widget = client.load_typed(&["owner", "repo"]).unwrap();
+        assert_eq!(loaded, widget);
+        assert_eq!(
+            client.load_typed_fresh::<Widget>(&["owner", "repo"]).unwrap(),
+            Some(widget)
+        );
+        assert_eq!(
+            client
+                .load_typed_fresh::<Widget>(&["owner", "missing"])
+                .unwrap(),
+            None
+        );
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_save_async_and_load_async_round_trip() {
+        let cache_dir = temp_cache_dir("async-round-trip");
+        let client = Client::new(cache_dir.clone(), "data".to_string());
+
+        client.save_async(&["owner", "repo"], b"hello").await.unwrap();
+        assert_eq!(
+            client.load_async(&["owner", "repo"]).await.unwrap(),
+            b"hello".to_vec()
+        );
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    /**
+     * Regresses the `!Send` `Clock` trait object: sharing one `Client`
+     * across concurrently spawned tasks only compiles, let alone passes,
+     * if every field (including `clock`) is `Send + Sync`.
+     */
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_shared_client_survives_concurrent_save_and_load() {
+        let cache_dir = temp_cache_dir("async-concurrent");
+        let client = Arc::new(Client::new(cache_dir.clone(), "data".to_string()));
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    let key = format!("repo-{}", i);
+                    client
+                        .save_async(&["owner", &key], b"hello")
+                        .await
+                        .unwrap();
+                    client.load_async(&["owner", &key]).await.unwrap()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), b"hello".to_vec());
+        }
+
+        fs::remove_dir_all(&cache_dir).ok();
+    }
 }
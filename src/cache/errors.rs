@@ -1,17 +1,52 @@
+use std::{fmt, io, path::PathBuf};
+
+/**
+ * Error names the cache file and operation that failed, instead of
+ * surfacing a bare io/serde error with no indication of which key path
+ * produced it.
+ */
 #[derive(Debug)]
 pub enum Error {
-    IOErr(std::io::Error),
-    DeserializeError(serde_json::Error),
+    ReadFailed { path: PathBuf, source: io::Error },
+    WriteFailed { path: PathBuf, source: io::Error },
+    RemoveFailed { path: PathBuf, source: io::Error },
+    SerializeFailed { source: serde_json::Error },
+    DeserializeFailed { path: PathBuf, source: serde_json::Error },
 }
 
-impl From<std::io::Error> for Error {
-    fn from(err: std::io::Error) -> Error {
-        Error::IOErr(err)
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ReadFailed { path, source } => {
+                write!(f, "failed to read cache entry {}: {}", path.display(), source)
+            }
+            Error::WriteFailed { path, source } => {
+                write!(f, "failed to write cache entry {}: {}", path.display(), source)
+            }
+            Error::RemoveFailed { path, source } => {
+                write!(f, "failed to remove cache entry {}: {}", path.display(), source)
+            }
+            Error::SerializeFailed { source } => {
+                write!(f, "failed to serialize value for cache: {}", source)
+            }
+            Error::DeserializeFailed { path, source } => write!(
+                f,
+                "failed to deserialize cache entry {}: {}",
+                path.display(),
+                source
+            ),
+        }
     }
 }
 
-impl From<serde_json::Error> for Error {
-    fn from(err: serde_json::Error) -> Error {
-        Error::DeserializeError(err)
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ReadFailed { source, .. } => Some(source),
+            Error::WriteFailed { source, .. } => Some(source),
+            Error::RemoveFailed { source, .. } => Some(source),
+            Error::SerializeFailed { source } => Some(source),
+            Error::DeserializeFailed { source, .. } => Some(source),
+        }
     }
 }
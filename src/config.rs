@@ -0,0 +1,40 @@
+/**
+ * Config resolution shared by `Client` and `AsyncClient`, so the two
+ * clients can't drift on how they read `CODECOV_CACHE_DIR` /
+ * `CODECOV_CACHE_COMPRESS` or how long mutable endpoints stay cached.
+ */
+use std::time::Duration;
+
+/**
+ * Mutable endpoints (repo/branch/commit listings, and branch detail
+ * without a pinned commit id) aren't keyed by an immutable id, so their
+ * cache entries are given a staleness window instead of being cached
+ * forever.
+ */
+pub(crate) const MUTABLE_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+pub(crate) fn resolve_cache_dir_root() -> std::path::PathBuf {
+    match std::env::var("CODECOV_CACHE_DIR") {
+        Ok(path) => std::path::PathBuf::from(path),
+        Err(_) => default_cache_dir_root(),
+    }
+}
+
+fn default_cache_dir_root() -> std::path::PathBuf {
+    let Some(mut path) = dirs::cache_dir() else {
+        panic!("Unsupported platform");
+    };
+    path.push("rust-codecov-cache");
+    path
+}
+
+/**
+ * resolve_compress_from_env reads CODECOV_CACHE_COMPRESS, so users
+ * can opt into gzip-compressed cache entries without code changes.
+ */
+pub(crate) fn resolve_compress_from_env() -> bool {
+    match std::env::var("CODECOV_CACHE_COMPRESS") {
+        Ok(value) => matches!(value.to_lowercase().as_str(), "1" | "true" | "yes" | "on"),
+        Err(_) => false,
+    }
+}
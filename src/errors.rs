@@ -5,6 +5,7 @@
 pub enum Error {
     EnvError(std::env::VarError),
     CodecovClientError(codecov::errors::Error),
+    CacheError(crate::cache::errors::Error),
 }
 
 impl From<codecov::errors::Error> for Error {
@@ -15,3 +16,9 @@ impl From<codecov::errors::Error> for Error {
         }
     }
 }
+
+impl From<crate::cache::errors::Error> for Error {
+    fn from(err: crate::cache::errors::Error) -> Error {
+        Error::CacheError(err)
+    }
+}
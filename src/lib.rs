@@ -1,4 +1,7 @@
+#[cfg(feature = "async")]
+pub mod async_client;
 pub mod cache;
+mod config;
 pub mod errors;
 
 use crate::errors::Error;
@@ -12,7 +15,10 @@ use codecov::{
  */
 pub struct Client {
     codecov_client: CodecovClient,
+    // Keyed by an immutable commit id, so entries never go stale.
     cache_client: cache::Client,
+    // Used for responses that can change over time, so entries expire.
+    mutable_cache_client: cache::Client,
 }
 
 /**
@@ -22,32 +28,30 @@ pub struct Client {
  */
 impl Client {
     pub fn new_from_env() -> Result<Client, Error> {
-        let cache_dir = Client::resolve_cache_dir_root();
+        let cache_dir = config::resolve_cache_dir_root();
+        let compress = config::resolve_compress_from_env();
         Ok(Client {
             codecov_client: CodecovClient::new_from_env()?,
-            cache_client: cache::Client::new(cache_dir, "data.json".to_string()),
+            cache_client: cache::Client::new(cache_dir.clone(), "data.json".to_string())
+                .with_compression(compress),
+            mutable_cache_client: cache::Client::new_with_ttl(
+                cache_dir,
+                "data.json".to_string(),
+                config::MUTABLE_CACHE_TTL,
+            )
+            .with_compression(compress),
         })
     }
 
-    fn resolve_cache_dir_root() -> std::path::PathBuf {
-        match std::env::var("CODECOV_CACHE_DIR") {
-            Ok(path) => std::path::PathBuf::from(path),
-            Err(_) => Client::default_cache_dir_root(),
-        }
-    }
-
-    fn default_cache_dir_root() -> std::path::PathBuf {
-        let Some(mut path) = dirs::cache_dir() else {
-            panic!("Unsupported platform");
-        };
-        path.push("rust-codecov-cache");
-        path
-    }
-
     pub fn new(token: String, cache_dir: std::path::PathBuf) -> Client {
         Client {
             codecov_client: CodecovClient::new(token),
-            cache_client: cache::Client::new(cache_dir, "data.json".to_string()),
+            cache_client: cache::Client::new(cache_dir.clone(), "data.json".to_string()),
+            mutable_cache_client: cache::Client::new_with_ttl(
+                cache_dir,
+                "data.json".to_string(),
+                config::MUTABLE_CACHE_TTL,
+            ),
         }
     }
 
@@ -57,7 +61,13 @@ impl Client {
      * This function will make multiple requests to get all repos.
      */
     pub fn get_all_repos(&self, owner: &Owner) -> Result<Vec<Repo>, Error> {
-        Ok(self.codecov_client.get_all_repos(owner)?)
+        let cache_key = &[&owner.service, &owner.username, "repos"];
+        if let Ok(Some(repos)) = self.mutable_cache_client.load_typed_fresh(cache_key) {
+            return Ok(repos);
+        }
+        let repos = self.codecov_client.get_all_repos(owner)?;
+        self.mutable_cache_client.save_typed(cache_key, &repos)?;
+        Ok(repos)
     }
 
     /**
@@ -65,7 +75,13 @@ impl Client {
      * https://docs.codecov.com/reference/repos_commits_list
      */
     pub fn get_commits(&self, author: &Author) -> Result<CommitsAPIResponse, Error> {
-        Ok(self.codecov_client.get_commits(author)?)
+        let cache_key = &[&author.service, &author.username, &author.name, "commits"];
+        if let Ok(Some(commits)) = self.mutable_cache_client.load_typed_fresh(cache_key) {
+            return Ok(commits);
+        }
+        let commits = self.codecov_client.get_commits(author)?;
+        self.mutable_cache_client.save_typed(cache_key, &commits)?;
+        Ok(commits)
     }
 
     /**
@@ -73,7 +89,13 @@ impl Client {
      * https://docs.codecov.com/reference/repos_branches_list
      */
     pub fn get_branches(&self, author: &Author) -> Result<BranchesAPIResponse, Error> {
-        Ok(self.codecov_client.get_branches(author)?)
+        let cache_key = &[&author.service, &author.username, &author.name, "branches"];
+        if let Ok(Some(branches)) = self.mutable_cache_client.load_typed_fresh(cache_key) {
+            return Ok(branches);
+        }
+        let branches = self.codecov_client.get_branches(author)?;
+        self.mutable_cache_client.save_typed(cache_key, &branches)?;
+        Ok(branches)
     }
 
     /**
@@ -85,7 +107,21 @@ impl Client {
         author: &Author,
         branch_name: &str,
     ) -> Result<BranchDetailAPIResponse, Error> {
-        Ok(self.codecov_client.get_branch_detail(author, branch_name)?)
+        let cache_key = &[
+            &author.service,
+            &author.username,
+            &author.name,
+            branch_name,
+            "branch-detail",
+        ];
+        if let Ok(Some(detail)) = self.mutable_cache_client.load_typed_fresh(cache_key) {
+            return Ok(detail);
+        }
+        let retrieved = self.codecov_client.get_branch_detail(author, branch_name)?;
+        if let BranchDetailAPIResponse::Success(_) = &retrieved {
+            self.mutable_cache_client.save_typed(cache_key, &retrieved)?;
+        }
+        Ok(retrieved)
     }
 
     /**
@@ -106,29 +142,21 @@ impl Client {
             commit_id,
         ];
         // Use cache if exists
-        if let Ok(data) = self.cache_client.load(cache_key) {
-            if let Ok(value) = serde_json::from_slice(&data) {
-                if let Ok(branch_detail) = serde_json::from_value(value) {
-                    return Ok(branch_detail);
-                }
-            }
+        if let Ok(branch_detail) = self.cache_client.load_typed(cache_key) {
+            return Ok(branch_detail);
         }
         // If cache does not exist, fetch from Codecov API
         let retrieved = self.codecov_client.get_branch_detail(author, branch_name)?;
         // Save to cache
         if let BranchDetailAPIResponse::Success(detail) = &retrieved {
-            if let Ok(data) = serde_json::to_vec(&detail) {
-                let cache_key = &[
-                    &author.service,
-                    &author.username,
-                    &author.name,
-                    branch_name,
-                    &detail.head_commit.commitid,
-                ];
-                if let Err(err) = self.cache_client.save(cache_key, &data) {
-                    println!("Failed to save cache: {:?}", err);
-                }
-            }
+            let cache_key = &[
+                &author.service,
+                &author.username,
+                &author.name,
+                branch_name,
+                &detail.head_commit.commitid,
+            ];
+            self.cache_client.save_typed(cache_key, detail)?;
             return Ok(retrieved);
         }
         Ok(retrieved)